@@ -0,0 +1,188 @@
+use std::convert::TryFrom;
+use anyhow::{anyhow, Result};
+
+/// The animation-wide control data carried by an APNG's `acTL` chunk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationControl {
+    pub num_frames: u32,
+    pub num_plays: u32,
+}
+
+impl AnimationControl {
+    pub const ACTL_PAYLOAD_SIZE: usize = 8;
+}
+
+impl TryFrom<&[u8]> for AnimationControl {
+    type Error = anyhow::Error;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != AnimationControl::ACTL_PAYLOAD_SIZE {
+            Err(anyhow!(
+                "acTL payload must be {} bytes, got {}",
+                AnimationControl::ACTL_PAYLOAD_SIZE,
+                data.len(),
+            ))?;
+        }
+
+        Ok(AnimationControl {
+            num_frames: u32::from_be_bytes(data[0..4].try_into()?),
+            num_plays: u32::from_be_bytes(data[4..8].try_into()?),
+        })
+    }
+}
+
+/// How the frame area is disposed of before rendering the next frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisposeOp {
+    None,
+    Background,
+    Previous,
+}
+
+impl TryFrom<u8> for DisposeOp {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DisposeOp::None),
+            1 => Ok(DisposeOp::Background),
+            2 => Ok(DisposeOp::Previous),
+            other => Err(anyhow!("Unrecognized fcTL dispose_op: {}", other)),
+        }
+    }
+}
+
+/// How the frame is blended onto the output buffer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlendOp {
+    Source,
+    Over,
+}
+
+impl TryFrom<u8> for BlendOp {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BlendOp::Source),
+            1 => Ok(BlendOp::Over),
+            other => Err(anyhow!("Unrecognized fcTL blend_op: {}", other)),
+        }
+    }
+}
+
+/// A single animation frame: the control data from its `fcTL` chunk, plus the
+/// total number of image data bytes in the `fdAT`/`IDAT` chunks associated with it
+/// (not counting the sequence number that prefixes each `fdAT` payload).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameControl {
+    pub sequence_number: u32,
+    pub width: u32,
+    pub height: u32,
+    pub x_offset: u32,
+    pub y_offset: u32,
+    pub delay_num: u16,
+    pub delay_den: u16,
+    pub dispose_op: DisposeOp,
+    pub blend_op: BlendOp,
+    pub data_length: usize,
+}
+
+impl FrameControl {
+    pub const FCTL_PAYLOAD_SIZE: usize = 26;
+    /// `fdAT` chunks prefix their data with a 4-byte sequence number not present in `IDAT`.
+    pub const FDAT_SEQUENCE_NUMBER_SIZE: usize = 4;
+
+    /// The frame's delay in seconds, per the APNG spec's rule that a zero
+    /// denominator means hundredths of a second.
+    pub fn delay_seconds(&self) -> f64 {
+        let den = if self.delay_den == 0 { 100.0 } else { self.delay_den as f64 };
+        self.delay_num as f64 / den
+    }
+}
+
+impl TryFrom<&[u8]> for FrameControl {
+    type Error = anyhow::Error;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != FrameControl::FCTL_PAYLOAD_SIZE {
+            Err(anyhow!(
+                "fcTL payload must be {} bytes, got {}",
+                FrameControl::FCTL_PAYLOAD_SIZE,
+                data.len(),
+            ))?;
+        }
+
+        Ok(FrameControl {
+            sequence_number: u32::from_be_bytes(data[0..4].try_into()?),
+            width: u32::from_be_bytes(data[4..8].try_into()?),
+            height: u32::from_be_bytes(data[8..12].try_into()?),
+            x_offset: u32::from_be_bytes(data[12..16].try_into()?),
+            y_offset: u32::from_be_bytes(data[16..20].try_into()?),
+            delay_num: u16::from_be_bytes(data[20..22].try_into()?),
+            delay_den: u16::from_be_bytes(data[22..24].try_into()?),
+            dispose_op: DisposeOp::try_from(data[24])?,
+            blend_op: BlendOp::try_from(data[25])?,
+            data_length: 0,
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn testing_actl_bytes() -> Vec<u8> {
+        let num_frames: u32 = 3;
+        let num_plays: u32 = 0;
+        num_frames.to_be_bytes().iter().chain(num_plays.to_be_bytes().iter()).copied().collect()
+    }
+
+    fn testing_fctl_bytes() -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_be_bytes()); // sequence_number
+        data.extend_from_slice(&100u32.to_be_bytes()); // width
+        data.extend_from_slice(&80u32.to_be_bytes()); // height
+        data.extend_from_slice(&0u32.to_be_bytes()); // x_offset
+        data.extend_from_slice(&0u32.to_be_bytes()); // y_offset
+        data.extend_from_slice(&1u16.to_be_bytes()); // delay_num
+        data.extend_from_slice(&30u16.to_be_bytes()); // delay_den
+        data.push(1); // dispose_op: Background
+        data.push(0); // blend_op: Source
+        data
+    }
+
+    #[test]
+    fn test_animation_control_from_bytes() {
+        let actl = AnimationControl::try_from(testing_actl_bytes().as_slice()).unwrap();
+        assert_eq!(actl.num_frames, 3);
+        assert_eq!(actl.num_plays, 0);
+    }
+
+    #[test]
+    fn test_frame_control_from_bytes() {
+        let fctl = FrameControl::try_from(testing_fctl_bytes().as_slice()).unwrap();
+
+        assert_eq!(fctl.sequence_number, 1);
+        assert_eq!(fctl.width, 100);
+        assert_eq!(fctl.height, 80);
+        assert_eq!(fctl.dispose_op, DisposeOp::Background);
+        assert_eq!(fctl.blend_op, BlendOp::Source);
+        assert_eq!(fctl.delay_seconds(), 1.0 / 30.0);
+    }
+
+    #[test]
+    fn test_frame_control_zero_delay_den_means_hundredths() {
+        let mut bytes = testing_fctl_bytes();
+        bytes[22..24].copy_from_slice(&0u16.to_be_bytes());
+
+        let fctl = FrameControl::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(fctl.delay_seconds(), 0.01);
+    }
+
+    #[test]
+    fn test_frame_control_rejects_wrong_size_payload() {
+        assert!(FrameControl::try_from([0u8; 10].as_slice()).is_err());
+    }
+}
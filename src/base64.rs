@@ -0,0 +1,137 @@
+use anyhow::{anyhow, Result};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
+/// Encodes `data` as standard (RFC 4648) base64, padded with `=` to a multiple of 4 chars.
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for group in data.chunks(3) {
+        let b0 = group[0];
+        let b1 = *group.get(1).unwrap_or(&0);
+        let b2 = *group.get(2).unwrap_or(&0);
+
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if group.len() > 1 {
+            ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            PAD as char
+        });
+        out.push(if group.len() > 2 {
+            ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            PAD as char
+        });
+    }
+
+    out
+}
+
+fn value_of(byte: u8) -> Result<u8> {
+    match byte {
+        b'A'..=b'Z' => Ok(byte - b'A'),
+        b'a'..=b'z' => Ok(byte - b'a' + 26),
+        b'0'..=b'9' => Ok(byte - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        other => Err(anyhow!("Invalid base64 character: {:?}", other as char)),
+    }
+}
+
+/// Decodes standard (RFC 4648) base64 text back into bytes.
+pub fn decode(text: &str) -> Result<Vec<u8>> {
+    let bytes = text.as_bytes();
+
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if !bytes.len().is_multiple_of(4) {
+        Err(anyhow!("Base64 input length must be a multiple of 4, got {}", bytes.len()))?;
+    }
+
+    let mut out = Vec::with_capacity(bytes.len() / 4 * 3);
+
+    for (i, group) in bytes.chunks(4).enumerate() {
+        let is_last_group = i == bytes.len() / 4 - 1;
+        let pad_count = group.iter().rev().take_while(|&&b| b == PAD).count();
+
+        if pad_count > 0 && !is_last_group {
+            Err(anyhow!("Base64 padding may only appear at the end of the input"))?;
+        }
+        if pad_count > 2 {
+            Err(anyhow!("Base64 group has too much padding"))?;
+        }
+
+        let v0 = value_of(group[0])?;
+        let v1 = value_of(group[1])?;
+        let v2 = if pad_count == 2 { 0 } else { value_of(group[2])? };
+        let v3 = if pad_count >= 1 { 0 } else { value_of(group[3])? };
+
+        out.push((v0 << 2) | (v1 >> 4));
+        if pad_count < 2 {
+            out.push((v1 << 4) | (v2 >> 2));
+        }
+        if pad_count < 1 {
+            out.push((v2 << 6) | v3);
+        }
+    }
+
+    Ok(out)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_no_remainder() {
+        assert_eq!(encode(b"Man"), "TWFu");
+    }
+
+    #[test]
+    fn test_encode_one_byte_remainder() {
+        assert_eq!(encode(b"M"), "TQ==");
+    }
+
+    #[test]
+    fn test_encode_two_byte_remainder() {
+        assert_eq!(encode(b"Ma"), "TWE=");
+    }
+
+    #[test]
+    fn test_encode_empty() {
+        assert_eq!(encode(b""), "");
+    }
+
+    #[test]
+    fn test_decode_matches_known_vector() {
+        assert_eq!(decode("TWFu").unwrap(), b"Man");
+        assert_eq!(decode("TWE=").unwrap(), b"Ma");
+        assert_eq!(decode("TQ==").unwrap(), b"M");
+    }
+
+    #[test]
+    fn test_round_trip_arbitrary_bytes() {
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode(&encode(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_length() {
+        assert!(decode("abc").is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_character() {
+        assert!(decode("TWF!").is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_equals_sign_not_in_trailing_padding_run() {
+        assert!(decode("AB=C").is_err());
+    }
+}
@@ -20,10 +20,27 @@ impl Chunk {
         Chunk::CHUNK_TYPE_SIZE + Chunk::CHUNK_LENGTH_SIZE + Chunk::CRC_SIZE
     );
 
+    pub const FIELD_TAG_SIZE: usize = 1;
+    pub const FIELD_LENGTH_SIZE: usize = 4;
+
     pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Self {
         Self { chunk_type, data, crc: LazyCell::new() }
     }
 
+    /// Packs `fields` into a tag-length-value payload suitable for a chunk's data:
+    /// each field is encoded as `tag (1 byte) || length (4-byte BE) || value`.
+    pub fn from_fields(fields: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut data = Vec::new();
+
+        for (tag, value) in fields {
+            data.push(*tag);
+            data.extend_from_slice(&(value.len() as u32).to_be_bytes());
+            data.extend_from_slice(value);
+        }
+
+        data
+    }
+
     fn compute_crc(chunk_code: &[u8; 4], data: &Vec<u8>) -> u32 {
         let crc: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
         let mut digest = crc.digest();
@@ -34,11 +51,6 @@ impl Chunk {
         digest.finalize()
     }
 
-    fn to_string(&self) -> String {
-        String::from_utf8_lossy(
-            self.as_bytes().as_slice()
-        ).to_string()
-    }
 }
 
 pub trait IChunk {
@@ -48,6 +60,7 @@ pub trait IChunk {
     fn crc(&self) -> u32;
     fn data_as_string(&self) -> Result<String>;
     fn as_bytes(&self) -> Vec<u8>;
+    fn fields(&self) -> Result<Vec<(u8, Vec<u8>)>>;
 }
 
 impl IChunk for Chunk {
@@ -81,6 +94,34 @@ impl IChunk for Chunk {
 
         [chunk_length_bytes, chunk_type, data, crc].concat()
     }
+
+    fn fields(&self) -> Result<Vec<(u8, Vec<u8>)>> {
+        let data = self.data();
+        let mut fields = Vec::new();
+        let mut pos = 0;
+
+        while pos < data.len() {
+            if pos + Chunk::FIELD_TAG_SIZE + Chunk::FIELD_LENGTH_SIZE > data.len() {
+                Err(anyhow!("Truncated field header at offset {}", pos))?;
+            }
+
+            let tag = data[pos];
+            pos += Chunk::FIELD_TAG_SIZE;
+
+            let length_bytes: [u8; 4] = data[pos..pos + Chunk::FIELD_LENGTH_SIZE].try_into()?;
+            let length: usize = u32::from_be_bytes(length_bytes).try_into()?;
+            pos += Chunk::FIELD_LENGTH_SIZE;
+
+            if pos + length > data.len() {
+                Err(anyhow!("Overlong field value for tag {}: claims {} bytes, only {} remain", tag, length, data.len() - pos))?;
+            }
+
+            fields.push((tag, data[pos..pos + length].to_vec()));
+            pos += length;
+        }
+
+        Ok(fields)
+    }
 }
 
 impl TryFrom<&[u8]> for Chunk {
@@ -147,20 +188,44 @@ impl TryFrom<&[u8]> for Chunk {
         
         let computed_crc = Chunk::compute_crc(&chunk_type.bytes(), &content);
         if computed_crc != expected_crc {
-             Err(anyhow!(
-                "CRC mismatch. Computed from input data: {}, expected: {}", 
-                computed_crc, 
-                expected_crc
-            ))?;
+            Err(CrcMismatch {
+                stored_crc: expected_crc,
+                computed_crc,
+                recover: Chunk::MIN_CHUNK_SIZE + content_size,
+            })?;
         }
 
         Ok(Chunk::new(chunk_type, content))
     }
 }
 
+/// A recoverable CRC failure: the stored and computed CRCs for a chunk disagree.
+///
+/// `recover` is the number of bytes, counted from the start of the offending chunk,
+/// a caller must skip to land back on the next chunk boundary (`4 + 4 + data.len() + 4`).
+/// This lets a parser resynchronize after a single damaged chunk instead of aborting.
+#[derive(Debug, Clone, Copy)]
+pub struct CrcMismatch {
+    pub stored_crc: u32,
+    pub computed_crc: u32,
+    pub recover: usize,
+}
+
+impl Display for CrcMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CRC mismatch. Computed from input data: {}, expected: {} (skip {} bytes to resynchronize)",
+            self.computed_crc, self.stored_crc, self.recover,
+        )
+    }
+}
+
+impl std::error::Error for CrcMismatch {}
+
 impl Display for Chunk {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.to_string())
+        write!(f, "{}", String::from_utf8_lossy(self.as_bytes().as_slice()))
     }
 }
 
@@ -272,6 +337,30 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_invalid_chunk_crc_error_carries_recovery_info() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let err = Chunk::try_from(chunk_data.as_ref()).unwrap_err();
+        let mismatch = err.downcast_ref::<CrcMismatch>().unwrap();
+
+        assert_eq!(mismatch.stored_crc, crc);
+        assert_eq!(mismatch.computed_crc, 2882656334);
+        assert_eq!(mismatch.recover, chunk_data.len());
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
@@ -292,4 +381,34 @@ mod tests {
         
         let _chunk_string = format!("{}", chunk);
     }
+
+    #[test]
+    fn test_fields_round_trip() {
+        let fields: Vec<(u8, Vec<u8>)> = vec![
+            (1, b"author".to_vec()),
+            (2, b"".to_vec()),
+            (3, b"2026-07-29".to_vec()),
+        ];
+
+        let data = Chunk::from_fields(&fields);
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), data);
+
+        assert_eq!(chunk.fields().unwrap(), fields);
+    }
+
+    #[test]
+    fn test_fields_rejects_truncated_header() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), vec![1, 0, 0]);
+        assert!(chunk.fields().is_err());
+    }
+
+    #[test]
+    fn test_fields_rejects_overlong_value() {
+        let mut data = vec![1];
+        data.extend_from_slice(&10u32.to_be_bytes());
+        data.extend_from_slice(b"short");
+
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), data);
+        assert!(chunk.fields().is_err());
+    }
 }
@@ -17,6 +17,11 @@ pub(crate) enum Command {
         /// The message to be encoded
         #[arg(required = true)]
         message: String,
+
+        /// Treat `message` as base64 and decode it into raw bytes before encoding,
+        /// so binary or non-UTF-8 payloads survive the round trip
+        #[arg(long)]
+        base64: bool,
     },
 
     /// Decode a message contained in a PNG image file
@@ -29,6 +34,14 @@ pub(crate) enum Command {
         /// A 4-byte ASCII string which will be used to decode the message
         #[arg(required = true)]
         chunk_type: String,
+
+        /// Print the chunk's raw bytes as base64 instead of lossy UTF-8
+        #[arg(long)]
+        base64: bool,
+
+        /// Tolerate CRC-corrupt chunks by skipping them instead of aborting the read
+        #[arg(long)]
+        lossy: bool,
     },
 
     /// Remove a message encoded in a PNG image file
@@ -49,5 +62,61 @@ pub(crate) enum Command {
         /// The path of the image
         #[arg(required = true)]
         path: PathBuf,
+
+        /// Tolerate CRC-corrupt chunks by skipping them instead of aborting the read
+        #[arg(long)]
+        lossy: bool,
+    },
+
+    /// Add a tagged field to a chunk's TLV field container, preserving any other fields
+    #[command()]
+    EncodeField {
+        /// The path of the image
+        #[arg(required = true)]
+        path: PathBuf,
+
+        /// A 4-byte ASCII string identifying the chunk holding the field container
+        #[arg(required = true)]
+        chunk_type: String,
+
+        /// The 1-byte tag identifying the field
+        #[arg(required = true)]
+        tag: u8,
+
+        /// The value to store for this field
+        #[arg(required = true)]
+        value: String,
+
+        /// Treat `value` as base64 and decode it into raw bytes before storing
+        #[arg(long)]
+        base64: bool,
+    },
+
+    /// Read a single tagged field from a chunk's TLV field container
+    #[command()]
+    DecodeField {
+        /// The path of the image
+        #[arg(required = true)]
+        path: PathBuf,
+
+        /// A 4-byte ASCII string identifying the chunk holding the field container
+        #[arg(required = true)]
+        chunk_type: String,
+
+        /// The 1-byte tag identifying the field to read
+        #[arg(required = true)]
+        tag: u8,
+
+        /// Print the field's raw bytes as base64 instead of lossy UTF-8
+        #[arg(long)]
+        base64: bool,
+    },
+
+    /// Prints the APNG animation structure (frame count, dimensions, delays) of a PNG file
+    #[command()]
+    Frames {
+        /// The path of the image
+        #[arg(required = true)]
+        path: PathBuf,
     },
 }
\ No newline at end of file
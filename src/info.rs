@@ -0,0 +1,134 @@
+use std::convert::TryFrom;
+use std::fmt::Display;
+use anyhow::{anyhow, Result};
+
+/// The `IHDR` color type, decoded from its raw byte value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorType {
+    Grayscale,
+    Rgb,
+    Palette,
+    GrayscaleAlpha,
+    Rgba,
+}
+
+impl TryFrom<u8> for ColorType {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ColorType::Grayscale),
+            2 => Ok(ColorType::Rgb),
+            3 => Ok(ColorType::Palette),
+            4 => Ok(ColorType::GrayscaleAlpha),
+            6 => Ok(ColorType::Rgba),
+            other => Err(anyhow!("Unrecognized IHDR color type: {}", other)),
+        }
+    }
+}
+
+impl Display for ColorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ColorType::Grayscale => "Grayscale",
+            ColorType::Rgb => "RGB",
+            ColorType::Palette => "Palette",
+            ColorType::GrayscaleAlpha => "Grayscale+Alpha",
+            ColorType::Rgba => "RGBA",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// The image metadata carried by a PNG's `IHDR` chunk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Info {
+    pub width: u32,
+    pub height: u32,
+    pub bit_depth: u8,
+    pub color_type: ColorType,
+    pub compression_method: u8,
+    pub filter_method: u8,
+    pub interlace_method: u8,
+}
+
+impl Info {
+    pub const IHDR_PAYLOAD_SIZE: usize = 13;
+}
+
+impl TryFrom<&[u8]> for Info {
+    type Error = anyhow::Error;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != Info::IHDR_PAYLOAD_SIZE {
+            Err(anyhow!(
+                "IHDR payload must be {} bytes, got {}",
+                Info::IHDR_PAYLOAD_SIZE,
+                data.len(),
+            ))?;
+        }
+
+        Ok(Info {
+            width: u32::from_be_bytes(data[0..4].try_into()?),
+            height: u32::from_be_bytes(data[4..8].try_into()?),
+            bit_depth: data[8],
+            color_type: ColorType::try_from(data[9])?,
+            compression_method: data[10],
+            filter_method: data[11],
+            interlace_method: data[12],
+        })
+    }
+}
+
+impl Display for Info {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}x{} pixels, {}-bit {}, compression method {}, filter method {}, interlace method {}",
+            self.width,
+            self.height,
+            self.bit_depth,
+            self.color_type,
+            self.compression_method,
+            self.filter_method,
+            self.interlace_method,
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn testing_ihdr_bytes() -> Vec<u8> {
+        vec![0, 0, 1, 0, 0, 0, 0, 200, 8, 6, 0, 0, 1]
+    }
+
+    #[test]
+    fn test_info_from_ihdr_bytes() {
+        let info = Info::try_from(testing_ihdr_bytes().as_slice()).unwrap();
+
+        assert_eq!(info.width, 256);
+        assert_eq!(info.height, 200);
+        assert_eq!(info.bit_depth, 8);
+        assert_eq!(info.color_type, ColorType::Rgba);
+        assert_eq!(info.compression_method, 0);
+        assert_eq!(info.filter_method, 0);
+        assert_eq!(info.interlace_method, 1);
+    }
+
+    #[test]
+    fn test_info_rejects_wrong_size_payload() {
+        assert!(Info::try_from([0u8; 12].as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_info_rejects_unknown_color_type() {
+        let mut bytes = testing_ihdr_bytes();
+        bytes[9] = 5;
+
+        assert!(Info::try_from(bytes.as_slice()).is_err());
+    }
+}
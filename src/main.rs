@@ -9,9 +9,12 @@ use crate::commands::Command;
 use crate::png::{IPng, Png};
 
 
+mod apng;
+mod base64;
 mod chunk;
 mod chunk_type;
 mod commands;
+mod info;
 mod png;
 
 
@@ -36,14 +39,29 @@ fn write_png(path: &Path, png: &Png) -> anyhow::Result<()> {
 }
 
 
+fn report_skipped_chunks(skipped: &[crate::png::SkippedChunk]) {
+    for s in skipped {
+        eprintln!(
+            "Warning: skipped corrupt chunk at offset {} (stored crc {}, computed crc {}, {} bytes skipped)",
+            s.offset, s.stored_crc, s.computed_crc, s.recover,
+        );
+    }
+}
+
+
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
     match &cli.command {
         Some(c) => match c {
-            Command::Encode { path, chunk_type, message} => {
+            Command::Encode { path, chunk_type, message, base64 } => {
                 let chunk_type_obj = ChunkType::from_str(chunk_type)?;
-                let chunk = Chunk::new(chunk_type_obj, message.as_bytes().to_vec());
+                let data = if *base64 {
+                    base64::decode(message)?
+                } else {
+                    message.as_bytes().to_vec()
+                };
+                let chunk = Chunk::new(chunk_type_obj, data);
                 let mut png = read_png_from_file(path)?;
 
                 png.append_chunk(chunk);
@@ -51,11 +69,26 @@ fn main() -> anyhow::Result<()> {
 
                 println!("Successfully encoded message in PNG file.")
             },
-            Command::Decode { path, chunk_type } => {
-                let mut png = read_png_from_file(path)?;
-                let chunk = png.remove_chunk(chunk_type)?;
-
-                println!("Found! Message:\n\t{}", chunk.data_as_string()?);
+            Command::Decode { path, chunk_type, base64: as_base64, lossy } => {
+                let chunk = if *lossy {
+                    let bytes = fs::read(path)?;
+                    let (png, skipped) = Png::try_from_lossy(bytes.as_slice())?;
+                    report_skipped_chunks(&skipped);
+
+                    png.chunk_by_type(chunk_type)
+                        .cloned()
+                        .ok_or_else(|| anyhow!("Chunk of type {} not found", chunk_type))?
+                } else {
+                    let reader = fs::File::open(path).map(std::io::BufReader::new)?;
+                    png::find_chunk_streaming(reader, chunk_type)?
+                        .ok_or_else(|| anyhow!("Chunk of type {} not found", chunk_type))?
+                };
+
+                if *as_base64 {
+                    println!("Found! Message (base64):\n\t{}", base64::encode(chunk.data()));
+                } else {
+                    println!("Found! Message:\n\t{}", chunk.data_as_string()?);
+                }
             }
             Command::Remove { path, chunk_type } => {
                 let mut png = read_png_from_file(path)?;
@@ -65,10 +98,93 @@ fn main() -> anyhow::Result<()> {
 
                 println!("Successfully removed chunk from PNG file.")
             }
-            Command::Print { path } => {
-                let png = read_png_from_file(path)?;
+            Command::Print { path, lossy } => {
+                let png = if *lossy {
+                    let bytes = fs::read(path)?;
+                    let (png, skipped) = Png::try_from_lossy(bytes.as_slice())?;
+                    report_skipped_chunks(&skipped);
+                    png
+                } else {
+                    read_png_from_file(path)?
+                };
+
+                match png.info() {
+                    Ok(info) => println!("{}\n", info),
+                    Err(e) => eprintln!("Warning: could not decode IHDR: {}", e),
+                }
+
                 println!("{}", png);
             }
+            Command::EncodeField { path, chunk_type, tag, value, base64: as_base64 } => {
+                let mut png = read_png_from_file(path)?;
+                let value_bytes = if *as_base64 {
+                    base64::decode(value)?
+                } else {
+                    value.as_bytes().to_vec()
+                };
+
+                let mut fields = png.chunk_by_type(chunk_type)
+                    .map(|c| c.fields())
+                    .transpose()?
+                    .unwrap_or_default();
+
+                fields.retain(|(t, _)| t != tag);
+                fields.push((*tag, value_bytes));
+
+                if png.chunk_by_type(chunk_type).is_some() {
+                    png.remove_chunk(chunk_type)?;
+                }
+
+                let chunk_type_obj = ChunkType::from_str(chunk_type)?;
+                png.append_chunk(Chunk::new(chunk_type_obj, Chunk::from_fields(&fields)));
+                write_png(path, &png)?;
+
+                println!("Successfully encoded field {} in PNG file.", tag)
+            }
+            Command::DecodeField { path, chunk_type, tag, base64: as_base64 } => {
+                let png = read_png_from_file(path)?;
+                let chunk = png.chunk_by_type(chunk_type)
+                    .ok_or_else(|| anyhow!("No chunk of type {} found", chunk_type))?;
+
+                let fields = chunk.fields()?;
+                let (_, value) = fields.iter()
+                    .find(|(t, _)| t == tag)
+                    .ok_or_else(|| anyhow!("No field with tag {} found in chunk {}", tag, chunk_type))?;
+
+                if *as_base64 {
+                    println!("Found! Field:\n\t{}", base64::encode(value));
+                } else {
+                    println!("Found! Field:\n\t{}", String::from_utf8_lossy(value));
+                }
+            }
+            Command::Frames { path } => {
+                let png = read_png_from_file(path)?;
+                let frames = png.frames();
+
+                match png.animation() {
+                    Ok(animation) => {
+                        let plays = if animation.num_plays == 0 {
+                            "loops forever".to_string()
+                        } else {
+                            format!("plays {} time(s)", animation.num_plays)
+                        };
+                        println!("Animation: {} declared frame(s), {}", animation.num_frames, plays);
+                    }
+                    Err(e) => println!("No animation control chunk found: {}", e),
+                }
+
+                if frames.is_empty() {
+                    println!("No animation frames found.");
+                } else {
+                    for (i, frame) in frames.iter().enumerate() {
+                        println!(
+                            "Frame {}: {}x{} at ({}, {}), delay {:.3}s, {} bytes of image data",
+                            i, frame.width, frame.height, frame.x_offset, frame.y_offset,
+                            frame.delay_seconds(), frame.data_length,
+                        );
+                    }
+                }
+            }
         },
         _ => return Err(anyhow!("No command specified")),
     }
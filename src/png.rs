@@ -0,0 +1,652 @@
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::io::Read;
+use anyhow::{anyhow, Result};
+use crate::apng::{AnimationControl, FrameControl};
+use crate::chunk::{Chunk, CrcMismatch, IChunk};
+use crate::chunk_type::{ChunkType, IChunkType};
+use crate::info::Info;
+
+#[derive(Debug, Clone)]
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl Png {
+    pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Self {
+        Self { chunks }
+    }
+}
+
+pub trait IPng {
+    fn header(&self) -> &[u8; 8];
+    fn chunks(&self) -> &[Chunk];
+    fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk>;
+    fn append_chunk(&mut self, chunk: Chunk);
+    fn remove_chunk(&mut self, chunk_type: &str) -> Result<Chunk>;
+    fn as_bytes(&self) -> Vec<u8>;
+    fn info(&self) -> Result<Info>;
+    fn animation(&self) -> Result<AnimationControl>;
+    fn frames(&self) -> Vec<FrameControl>;
+}
+
+impl IPng for Png {
+    fn header(&self) -> &[u8; 8] {
+        &Png::STANDARD_HEADER
+    }
+
+    fn chunks(&self) -> &[Chunk] {
+        self.chunks.as_slice()
+    }
+
+    fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|c| c.chunk_type().to_string() == chunk_type)
+    }
+
+    fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    fn remove_chunk(&mut self, chunk_type: &str) -> Result<Chunk> {
+        let pos = self.chunks
+            .iter()
+            .position(|c| c.chunk_type().to_string() == chunk_type)
+            .ok_or_else(|| anyhow!("Chunk of type {} not found", chunk_type))?;
+
+        Ok(self.chunks.remove(pos))
+    }
+
+    fn as_bytes(&self) -> Vec<u8> {
+        self.header()
+            .iter()
+            .copied()
+            .chain(self.chunks.iter().flat_map(|c| c.as_bytes()))
+            .collect()
+    }
+
+    fn info(&self) -> Result<Info> {
+        self.chunk_by_type("IHDR")
+            .ok_or_else(|| anyhow!("PNG has no IHDR chunk"))
+            .and_then(|c| Info::try_from(c.data()))
+    }
+
+    fn animation(&self) -> Result<AnimationControl> {
+        self.chunk_by_type("acTL")
+            .ok_or_else(|| anyhow!("PNG has no acTL chunk"))
+            .and_then(|c| AnimationControl::try_from(c.data()))
+    }
+
+    fn frames(&self) -> Vec<FrameControl> {
+        let mut frames = Vec::new();
+        let mut current: Option<FrameControl> = None;
+
+        for chunk in self.chunks() {
+            match chunk.chunk_type().to_string().as_str() {
+                "fcTL" => {
+                    if let Some(frame) = current.take() {
+                        frames.push(frame);
+                    }
+                    if let Ok(frame) = FrameControl::try_from(chunk.data()) {
+                        current = Some(frame);
+                    }
+                }
+                "fdAT" => {
+                    if let Some(frame) = current.as_mut() {
+                        frame.data_length += chunk.data().len()
+                            .saturating_sub(FrameControl::FDAT_SEQUENCE_NUMBER_SIZE);
+                    }
+                }
+                "IDAT" => {
+                    if let Some(frame) = current.as_mut() {
+                        frame.data_length += chunk.data().len();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(frame) = current.take() {
+            frames.push(frame);
+        }
+
+        frames
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() < Png::STANDARD_HEADER.len() {
+            Err(anyhow!("Input is too short to contain a PNG header"))?;
+        }
+
+        let (header, mut rest) = bytes.split_at(Png::STANDARD_HEADER.len());
+        if header != Png::STANDARD_HEADER {
+            Err(anyhow!("Input does not start with the PNG signature"))?;
+        }
+
+        let mut chunks = Vec::new();
+        while !rest.is_empty() {
+            if rest.len() < Chunk::CHUNK_LENGTH_SIZE {
+                Err(anyhow!("Malformed input: trailing bytes do not form a full chunk"))?;
+            }
+
+            let length_bytes: [u8; 4] = rest[..Chunk::CHUNK_LENGTH_SIZE].try_into()?;
+            let content_size: usize = u32::from_be_bytes(length_bytes).try_into()?;
+            let chunk_size = Chunk::MIN_CHUNK_SIZE + content_size;
+
+            if rest.len() < chunk_size {
+                Err(anyhow!("Malformed input: chunk is truncated"))?;
+            }
+
+            let (chunk_bytes, remainder) = rest.split_at(chunk_size);
+            chunks.push(Chunk::try_from(chunk_bytes)?);
+            rest = remainder;
+        }
+
+        Ok(Png::from_chunks(chunks))
+    }
+}
+
+impl Display for Png {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let header = String::from_utf8_lossy(&Png::STANDARD_HEADER).to_string();
+        let chunks = self.chunks
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        write!(f, "{}\n{}", header, chunks)
+    }
+}
+
+/// A chunk that failed its CRC check and was skipped while parsing with [`Png::try_from_lossy`].
+#[derive(Debug, Clone, Copy)]
+pub struct SkippedChunk {
+    /// Offset of the damaged chunk, in bytes from the start of the data following the signature.
+    pub offset: usize,
+    pub stored_crc: u32,
+    pub computed_crc: u32,
+    /// Number of bytes that were skipped to resynchronize on the next chunk boundary.
+    pub recover: usize,
+}
+
+impl Png {
+    /// Parses `bytes` the same way as [`Png::try_from`], but tolerates chunks whose CRC
+    /// doesn't match: a damaged chunk is skipped rather than aborting the whole parse.
+    /// Returns the chunks that parsed successfully alongside a record of what was skipped.
+    pub fn try_from_lossy(bytes: &[u8]) -> Result<(Png, Vec<SkippedChunk>)> {
+        if bytes.len() < Png::STANDARD_HEADER.len() {
+            Err(anyhow!("Input is too short to contain a PNG header"))?;
+        }
+
+        let (header, mut rest) = bytes.split_at(Png::STANDARD_HEADER.len());
+        if header != Png::STANDARD_HEADER {
+            Err(anyhow!("Input does not start with the PNG signature"))?;
+        }
+
+        let mut chunks = Vec::new();
+        let mut skipped = Vec::new();
+        let mut offset = 0usize;
+
+        while !rest.is_empty() {
+            if rest.len() < Chunk::CHUNK_LENGTH_SIZE {
+                Err(anyhow!("Malformed input: trailing bytes do not form a full chunk"))?;
+            }
+
+            let length_bytes: [u8; 4] = rest[..Chunk::CHUNK_LENGTH_SIZE].try_into()?;
+            let content_size: usize = u32::from_be_bytes(length_bytes).try_into()?;
+            let chunk_size = Chunk::MIN_CHUNK_SIZE + content_size;
+
+            if rest.len() < chunk_size {
+                Err(anyhow!("Malformed input: chunk is truncated"))?;
+            }
+
+            let (chunk_bytes, remainder) = rest.split_at(chunk_size);
+            match Chunk::try_from(chunk_bytes) {
+                Ok(chunk) => chunks.push(chunk),
+                Err(e) => match e.downcast_ref::<CrcMismatch>() {
+                    Some(mismatch) => skipped.push(SkippedChunk {
+                        offset,
+                        stored_crc: mismatch.stored_crc,
+                        computed_crc: mismatch.computed_crc,
+                        recover: mismatch.recover,
+                    }),
+                    None => Err(anyhow!("Unrecoverable error parsing chunk at offset {}: {}", offset, e))?,
+                },
+            }
+
+            offset += chunk_size;
+            rest = remainder;
+        }
+
+        Ok((Png::from_chunks(chunks), skipped))
+    }
+}
+
+
+/// Events emitted by [`PngDecoder`] as it makes progress through an incremental parse.
+#[derive(Debug, Clone)]
+pub enum DecodeEvent {
+    /// The 8-byte PNG signature has been read and matches the expected magic.
+    SignatureVerified,
+    /// A chunk's length and type have been read; its data is about to follow.
+    ChunkBegin { chunk_type: ChunkType, length: u32 },
+    /// A full chunk, including a verified CRC, has been assembled.
+    ChunkComplete(Chunk),
+    /// The `IEND` chunk has been processed; no further chunks are expected.
+    End,
+}
+
+/// The result of a single [`PngDecoder::pull`] call.
+#[derive(Debug, Clone)]
+pub enum PullResult {
+    /// A state transition completed and produced an event.
+    Event(DecodeEvent),
+    /// Not enough buffered input to complete the next transition yet.
+    Nothing,
+}
+
+#[derive(Debug, Clone)]
+enum DecodeState {
+    Signature,
+    Length,
+    Type { length: u32 },
+    ChunkData { chunk_type: ChunkType, length: usize },
+    Crc { chunk_type: ChunkType, data: Vec<u8> },
+    /// The `IEND` chunk has been processed; `End` still needs to be emitted once.
+    Finished,
+    /// `End` has already been emitted; every further `pull()` returns `Nothing`.
+    Ended,
+}
+
+/// An incremental, pull-based PNG parser.
+///
+/// Bytes are fed in via [`PngDecoder::feed`] as they become available (e.g. from a
+/// [`std::io::Read`] stream), and [`PngDecoder::pull`] is called repeatedly to drain
+/// completed transitions as [`DecodeEvent`]s. This lets a caller process a PNG without
+/// ever holding the whole file in memory at once.
+#[derive(Debug, Clone)]
+pub struct PngDecoder {
+    state: DecodeState,
+    buffer: Vec<u8>,
+}
+
+impl PngDecoder {
+    pub fn new() -> Self {
+        Self { state: DecodeState::Signature, buffer: Vec::new() }
+    }
+
+    /// Appends more input to the decoder's internal buffer.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Advances the state machine as far as the currently buffered input allows,
+    /// returning the next event or `PullResult::Nothing` if more input is needed.
+    pub fn pull(&mut self) -> Result<PullResult> {
+        loop {
+            match std::mem::replace(&mut self.state, DecodeState::Finished) {
+                DecodeState::Signature => {
+                    if self.buffer.len() < Png::STANDARD_HEADER.len() {
+                        self.state = DecodeState::Signature;
+                        return Ok(PullResult::Nothing);
+                    }
+
+                    let signature: Vec<u8> = self.buffer.drain(..Png::STANDARD_HEADER.len()).collect();
+                    if signature.as_slice() != Png::STANDARD_HEADER {
+                        Err(anyhow!("Input does not start with the PNG signature"))?;
+                    }
+
+                    self.state = DecodeState::Length;
+                    return Ok(PullResult::Event(DecodeEvent::SignatureVerified));
+                }
+                DecodeState::Length => {
+                    if self.buffer.len() < Chunk::CHUNK_LENGTH_SIZE {
+                        self.state = DecodeState::Length;
+                        return Ok(PullResult::Nothing);
+                    }
+
+                    let length_bytes: [u8; 4] = self.buffer
+                        .drain(..Chunk::CHUNK_LENGTH_SIZE)
+                        .collect::<Vec<u8>>()
+                        .try_into()
+                        .map_err(|e| anyhow!("Expected 4-byte length, got: {:?}", e))?;
+
+                    self.state = DecodeState::Type { length: u32::from_be_bytes(length_bytes) };
+                }
+                DecodeState::Type { length } => {
+                    if self.buffer.len() < Chunk::CHUNK_TYPE_SIZE {
+                        self.state = DecodeState::Type { length };
+                        return Ok(PullResult::Nothing);
+                    }
+
+                    let type_bytes: [u8; 4] = self.buffer
+                        .drain(..Chunk::CHUNK_TYPE_SIZE)
+                        .collect::<Vec<u8>>()
+                        .try_into()
+                        .map_err(|e| anyhow!("Expected 4-byte chunk type, got: {:?}", e))?;
+
+                    let chunk_type = ChunkType::try_from(type_bytes)?;
+                    self.state = DecodeState::ChunkData { chunk_type: chunk_type.clone(), length: length as usize };
+                    return Ok(PullResult::Event(DecodeEvent::ChunkBegin { chunk_type, length }));
+                }
+                DecodeState::ChunkData { chunk_type, length } => {
+                    if self.buffer.len() < length {
+                        self.state = DecodeState::ChunkData { chunk_type, length };
+                        return Ok(PullResult::Nothing);
+                    }
+
+                    let data: Vec<u8> = self.buffer.drain(..length).collect();
+                    self.state = DecodeState::Crc { chunk_type, data };
+                }
+                DecodeState::Crc { chunk_type, data } => {
+                    if self.buffer.len() < Chunk::CRC_SIZE {
+                        self.state = DecodeState::Crc { chunk_type, data };
+                        return Ok(PullResult::Nothing);
+                    }
+
+                    let crc_bytes: Vec<u8> = self.buffer.drain(..Chunk::CRC_SIZE).collect();
+
+                    let chunk_bytes: Vec<u8> = (data.len() as u32).to_be_bytes().iter()
+                        .chain(chunk_type.bytes().iter())
+                        .chain(data.iter())
+                        .chain(crc_bytes.iter())
+                        .copied()
+                        .collect();
+                    let chunk = Chunk::try_from(chunk_bytes.as_slice())?;
+
+                    self.state = if chunk_type.to_string() == "IEND" {
+                        DecodeState::Finished
+                    } else {
+                        DecodeState::Length
+                    };
+
+                    return Ok(PullResult::Event(DecodeEvent::ChunkComplete(chunk)));
+                }
+                DecodeState::Finished => {
+                    self.state = DecodeState::Ended;
+                    return Ok(PullResult::Event(DecodeEvent::End));
+                }
+                DecodeState::Ended => {
+                    self.state = DecodeState::Ended;
+                    return Ok(PullResult::Nothing);
+                }
+            }
+        }
+    }
+}
+
+impl Default for PngDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads from `reader` only as far as needed to find the first chunk of `target_type`,
+/// using a [`PngDecoder`] so the whole PNG never has to be buffered in memory at once.
+/// Returns `None` if the stream ends (or reaches `IEND`) without a matching chunk.
+pub fn find_chunk_streaming<R: Read>(mut reader: R, target_type: &str) -> Result<Option<Chunk>> {
+    let mut decoder = PngDecoder::new();
+    let mut buf = [0u8; 8192];
+
+    loop {
+        match decoder.pull()? {
+            PullResult::Event(DecodeEvent::ChunkComplete(chunk)) => {
+                if chunk.chunk_type().to_string() == target_type {
+                    return Ok(Some(chunk));
+                }
+            }
+            PullResult::Event(DecodeEvent::End) => return Ok(None),
+            PullResult::Event(_) => {}
+            PullResult::Nothing => {
+                let n = reader.read(&mut buf)?;
+                if n == 0 {
+                    return Ok(None);
+                }
+                decoder.feed(&buf[..n]);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn chunk_bytes(chunk_type: &str, data: &[u8]) -> Vec<u8> {
+        let chunk = Chunk::new(ChunkType::from_str(chunk_type).unwrap(), data.to_vec());
+        chunk.as_bytes()
+    }
+
+    fn testing_png_bytes() -> Vec<u8> {
+        let ihdr = chunk_bytes("IHDR", &[0, 0, 0, 1, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+        let iend = chunk_bytes("IEND", &[]);
+
+        Png::STANDARD_HEADER.iter()
+            .chain(ihdr.iter())
+            .chain(iend.iter())
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_try_from_lossy_skips_corrupt_chunk_and_keeps_the_rest() {
+        let mut ihdr = chunk_bytes("IHDR", &[0, 0, 0, 1, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+        let last = ihdr.len() - 1;
+        ihdr[last] ^= 0xff; // corrupt the CRC so IHDR fails its check
+        let rust_chunk = chunk_bytes("RuSt", b"hello");
+        let iend = chunk_bytes("IEND", &[]);
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER.iter()
+            .chain(ihdr.iter())
+            .chain(rust_chunk.iter())
+            .chain(iend.iter())
+            .copied()
+            .collect();
+
+        let (png, skipped) = Png::try_from_lossy(bytes.as_slice()).unwrap();
+
+        assert_eq!(png.chunks().len(), 2);
+        assert!(png.chunk_by_type("RuSt").is_some());
+        assert!(png.chunk_by_type("IEND").is_some());
+        assert!(png.chunk_by_type("IHDR").is_none());
+
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].offset, 0);
+        assert_eq!(skipped[0].recover, ihdr.len());
+    }
+
+    #[test]
+    fn test_png_from_chunks() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), vec![1, 2, 3]);
+        let png = Png::from_chunks(vec![chunk]);
+        assert_eq!(png.chunks().len(), 1);
+    }
+
+    #[test]
+    fn test_valid_png_from_bytes() {
+        let bytes = testing_png_bytes();
+        let png = Png::try_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(png.chunks().len(), 2);
+        assert!(png.chunk_by_type("IHDR").is_some());
+        assert!(png.chunk_by_type("IEND").is_some());
+    }
+
+    #[test]
+    fn test_invalid_png_signature() {
+        let mut bytes = testing_png_bytes();
+        bytes[0] = 0;
+
+        assert!(Png::try_from(bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_png_as_bytes_round_trips() {
+        let bytes = testing_png_bytes();
+        let png = Png::try_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(png.as_bytes(), bytes);
+    }
+
+    fn testing_fctl_bytes(sequence_number: u32, delay_num: u16) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&sequence_number.to_be_bytes());
+        data.extend_from_slice(&10u32.to_be_bytes()); // width
+        data.extend_from_slice(&10u32.to_be_bytes()); // height
+        data.extend_from_slice(&0u32.to_be_bytes()); // x_offset
+        data.extend_from_slice(&0u32.to_be_bytes()); // y_offset
+        data.extend_from_slice(&delay_num.to_be_bytes());
+        data.extend_from_slice(&10u16.to_be_bytes()); // delay_den
+        data.push(0); // dispose_op
+        data.push(0); // blend_op
+        data
+    }
+
+    #[test]
+    fn test_png_frames_associates_data_chunks() {
+        let actl = chunk_bytes("acTL", &[0, 0, 0, 2, 0, 0, 0, 0]);
+        let fctl0 = chunk_bytes("fcTL", &testing_fctl_bytes(0, 1));
+        let idat = chunk_bytes("IDAT", b"first-frame-data");
+        let fctl1 = chunk_bytes("fcTL", &testing_fctl_bytes(1, 2));
+        let mut fdat_payload = 1u32.to_be_bytes().to_vec();
+        fdat_payload.extend_from_slice(b"second-frame-data");
+        let fdat = chunk_bytes("fdAT", &fdat_payload);
+        let iend = chunk_bytes("IEND", &[]);
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER.iter()
+            .chain(actl.iter())
+            .chain(fctl0.iter())
+            .chain(idat.iter())
+            .chain(fctl1.iter())
+            .chain(fdat.iter())
+            .chain(iend.iter())
+            .copied()
+            .collect();
+
+        let png = Png::try_from(bytes.as_slice()).unwrap();
+        let animation = png.animation().unwrap();
+        assert_eq!(animation.num_frames, 2);
+
+        let frames = png.frames();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].sequence_number, 0);
+        assert_eq!(frames[0].data_length, b"first-frame-data".len());
+        assert_eq!(frames[1].sequence_number, 1);
+        assert_eq!(frames[1].data_length, b"second-frame-data".len());
+    }
+
+    #[test]
+    fn test_png_frames_empty_when_not_animated() {
+        let png = Png::try_from(testing_png_bytes().as_slice()).unwrap();
+        assert!(png.frames().is_empty());
+        assert!(png.animation().is_err());
+    }
+
+    #[test]
+    fn test_png_info_decodes_ihdr() {
+        let png = Png::try_from(testing_png_bytes().as_slice()).unwrap();
+        let info = png.info().unwrap();
+
+        assert_eq!(info.width, 1);
+        assert_eq!(info.height, 1);
+        assert_eq!(info.bit_depth, 8);
+        assert_eq!(info.color_type, crate::info::ColorType::Rgb);
+    }
+
+    #[test]
+    fn test_png_info_without_ihdr_is_an_error() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), vec![1, 2, 3]);
+        let png = Png::from_chunks(vec![chunk]);
+
+        assert!(png.info().is_err());
+    }
+
+    #[test]
+    fn test_png_append_and_remove_chunk() {
+        let mut png = Png::try_from(testing_png_bytes().as_slice()).unwrap();
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), vec![1, 2, 3]);
+
+        png.append_chunk(chunk);
+        assert!(png.chunk_by_type("RuSt").is_some());
+
+        let removed = png.remove_chunk("RuSt").unwrap();
+        assert_eq!(removed.chunk_type().to_string(), "RuSt");
+        assert!(png.chunk_by_type("RuSt").is_none());
+    }
+
+    #[test]
+    fn test_remove_missing_chunk_is_an_error() {
+        let mut png = Png::try_from(testing_png_bytes().as_slice()).unwrap();
+        assert!(png.remove_chunk("RuSt").is_err());
+    }
+
+    #[test]
+    fn test_decoder_emits_expected_event_sequence() {
+        let bytes = testing_png_bytes();
+        let mut decoder = PngDecoder::new();
+        decoder.feed(&bytes);
+
+        let mut events = Vec::new();
+        loop {
+            match decoder.pull().unwrap() {
+                PullResult::Event(event @ DecodeEvent::End) => {
+                    events.push(event);
+                    break;
+                }
+                PullResult::Event(event) => events.push(event),
+                PullResult::Nothing => break,
+            }
+        }
+
+        assert_eq!(events.len(), 6);
+        assert!(matches!(&events[0], DecodeEvent::SignatureVerified));
+        assert!(matches!(&events[1], DecodeEvent::ChunkBegin { chunk_type, .. } if chunk_type.to_string() == "IHDR"));
+        assert!(matches!(&events[2], DecodeEvent::ChunkComplete(c) if c.chunk_type().to_string() == "IHDR"));
+        assert!(matches!(&events[3], DecodeEvent::ChunkBegin { chunk_type, .. } if chunk_type.to_string() == "IEND"));
+        assert!(matches!(&events[4], DecodeEvent::ChunkComplete(c) if c.chunk_type().to_string() == "IEND"));
+        assert!(matches!(&events[5], DecodeEvent::End));
+    }
+
+    #[test]
+    fn test_decoder_handles_byte_by_byte_feeding() {
+        let bytes = testing_png_bytes();
+        let mut decoder = PngDecoder::new();
+        let mut completed_chunks = 0;
+
+        for byte in bytes {
+            decoder.feed(&[byte]);
+            while let PullResult::Event(event) = decoder.pull().unwrap() {
+                match event {
+                    DecodeEvent::ChunkComplete(_) => completed_chunks += 1,
+                    DecodeEvent::End => break,
+                    _ => {}
+                }
+            }
+        }
+
+        assert_eq!(completed_chunks, 2);
+    }
+
+    #[test]
+    fn test_find_chunk_streaming_locates_target_chunk() {
+        let bytes = testing_png_bytes();
+        let found = find_chunk_streaming(bytes.as_slice(), "IHDR").unwrap().unwrap();
+        assert_eq!(found.chunk_type().to_string(), "IHDR");
+    }
+
+    #[test]
+    fn test_find_chunk_streaming_returns_none_for_missing_type() {
+        let bytes = testing_png_bytes();
+        assert!(find_chunk_streaming(bytes.as_slice(), "RuSt").unwrap().is_none());
+    }
+}